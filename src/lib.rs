@@ -1,6 +1,243 @@
 /// Fast-parsing constructs and operations
 pub mod fastparse {
 
+    /// Fast-parsing iterators.
+    pub mod iter {
+
+        use super::types::PositionalSlice;
+
+
+        /// Iterator that splits a source slice on elements matching a
+        /// predicate, yielding [`PositionalSlice`]s describing each
+        /// token's `offset`/`length` into the source.
+        ///
+        /// Mirrors the semantics of core's `slice::Split`, including
+        /// emitting a final (possibly empty) trailing slice.
+        pub struct SplitPositional<'a, T, P>
+        where
+            P : FnMut(&T) -> bool,
+        {
+            source :   &'a [T],
+            pred :     P,
+            offset :   usize,
+            finished : bool,
+        }
+
+        impl<'a, T, P> SplitPositional<'a, T, P>
+        where
+            P : FnMut(&T) -> bool,
+        {
+            pub(crate) fn new(
+                source : &'a [T],
+                pred : P,
+            ) -> Self {
+                Self {
+                    source,
+                    pred,
+                    offset : 0,
+                    finished : false,
+                }
+            }
+        }
+
+        impl<'a, T, P> Iterator for SplitPositional<'a, T, P>
+        where
+            P : FnMut(&T) -> bool,
+        {
+            type Item = PositionalSlice;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.finished {
+                    return None;
+                }
+
+                let rest = &self.source[self.offset..];
+
+                match rest.iter().position(|x| (self.pred)(x)) {
+                    Some(ix) => {
+                        let slice = PositionalSlice::new(self.offset, ix);
+
+                        self.offset += ix + 1;
+
+                        Some(slice)
+                    },
+                    None => {
+                        let slice = PositionalSlice::new(self.offset, rest.len());
+
+                        self.offset = self.source.len();
+                        self.finished = true;
+
+                        Some(slice)
+                    },
+                }
+            }
+        }
+
+
+        /// Iterator that splits a source slice on elements matching a
+        /// predicate, yielding [`PositionalSlice`]s that retain the
+        /// matched delimiter at the end of each slice.
+        ///
+        /// Mirrors the semantics of core's `slice::SplitInclusive`: no
+        /// trailing empty slice is emitted after a final delimiter.
+        pub struct SplitInclusivePositional<'a, T, P>
+        where
+            P : FnMut(&T) -> bool,
+        {
+            source :   &'a [T],
+            pred :     P,
+            offset :   usize,
+            finished : bool,
+        }
+
+        impl<'a, T, P> SplitInclusivePositional<'a, T, P>
+        where
+            P : FnMut(&T) -> bool,
+        {
+            pub(crate) fn new(
+                source : &'a [T],
+                pred : P,
+            ) -> Self {
+                Self {
+                    source,
+                    pred,
+                    offset : 0,
+                    finished : false,
+                }
+            }
+        }
+
+        impl<'a, T, P> Iterator for SplitInclusivePositional<'a, T, P>
+        where
+            P : FnMut(&T) -> bool,
+        {
+            type Item = PositionalSlice;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.finished {
+                    return None;
+                }
+
+                if self.offset == self.source.len() {
+                    self.finished = true;
+
+                    return None;
+                }
+
+                let rest = &self.source[self.offset..];
+
+                match rest.iter().position(|x| (self.pred)(x)) {
+                    Some(ix) => {
+                        let len = ix + 1;
+                        let slice = PositionalSlice::new(self.offset, len);
+
+                        self.offset += len;
+
+                        Some(slice)
+                    },
+                    None => {
+                        let slice = PositionalSlice::new(self.offset, rest.len());
+
+                        self.offset = self.source.len();
+                        self.finished = true;
+
+                        Some(slice)
+                    },
+                }
+            }
+        }
+
+
+        /// Iterator over fixed-width, overlapping windows of a source
+        /// slice, yielding [`PositionalSlice`]s describing each window's
+        /// `offset`/`length` into the source.
+        ///
+        /// Mirrors the semantics of core's `slice::Windows`.
+        pub struct WindowsPositional<'a, T> {
+            source : &'a [T],
+            size :   usize,
+            offset : usize,
+        }
+
+        impl<'a, T> WindowsPositional<'a, T> {
+            pub(crate) fn new(
+                source : &'a [T],
+                size : usize,
+            ) -> Self {
+                assert!(0 != size, "window size must be non-zero");
+
+                Self {
+                    source,
+                    size,
+                    offset : 0,
+                }
+            }
+        }
+
+        impl<'a, T> Iterator for WindowsPositional<'a, T> {
+            type Item = PositionalSlice;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.offset + self.size > self.source.len() {
+                    return None;
+                }
+
+                let slice = PositionalSlice::new(self.offset, self.size);
+
+                self.offset += 1;
+
+                Some(slice)
+            }
+        }
+
+
+        /// Iterator over fixed-width, non-overlapping chunks of a source
+        /// slice, yielding [`PositionalSlice`]s describing each chunk's
+        /// `offset`/`length` into the source (the final chunk may be
+        /// shorter than `size`).
+        ///
+        /// Mirrors the semantics of core's `slice::Chunks`.
+        pub struct ChunksPositional<'a, T> {
+            source : &'a [T],
+            size :   usize,
+            offset : usize,
+        }
+
+        impl<'a, T> ChunksPositional<'a, T> {
+            pub(crate) fn new(
+                source : &'a [T],
+                size : usize,
+            ) -> Self {
+                assert!(0 != size, "chunk size must be non-zero");
+
+                Self {
+                    source,
+                    size,
+                    offset : 0,
+                }
+            }
+        }
+
+        impl<'a, T> Iterator for ChunksPositional<'a, T> {
+            type Item = PositionalSlice;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.offset >= self.source.len() {
+                    return None;
+                }
+
+                let remaining = self.source.len() - self.offset;
+                let len = std::cmp::min(self.size, remaining);
+
+                let slice = PositionalSlice::new(self.offset, len);
+
+                self.offset += len;
+
+                Some(slice)
+            }
+        }
+    }
+
     /// Fast-parsing types.
     pub mod types {
 
@@ -10,6 +247,84 @@ pub mod fastparse {
         };
 
 
+        /// The width, in bytes, of a `usize` word, used by the
+        /// word-at-a-time (SWAR) byte scan.
+        const USIZE_BYTES : usize = std::mem::size_of::<usize>();
+
+        /// Broadcasts `0x01` into every byte of a `usize`, derived from
+        /// the word width so it is correct regardless of target
+        /// pointer size.
+        const LO_BYTES : usize = usize::MAX / 0xFF;
+
+        /// Broadcasts `0x80` into every byte of a `usize`.
+        const HI_BYTES : usize = LO_BYTES << 7;
+
+        /// Locates the address index, within the word, of the
+        /// lowest-addressed *genuine* matching byte, given the SWAR
+        /// "found" mask and the `y` (`word ^ mask`) value it was
+        /// derived from.
+        ///
+        /// The borrow out of a genuine zero byte in
+        /// `y.wrapping_sub(LO_BYTES)` propagates towards more
+        /// significant bytes, which on a big-endian target lie at lower
+        /// addresses, so there the lowest-addressed set byte in `found`
+        /// can be a false positive. So each candidate, from the bottom
+        /// up, is verified against `y` (a true match's byte in `y` is
+        /// exactly `0`) before being accepted.
+        ///
+        /// Uses `to_ne_bytes()`, so it is correct for either target
+        /// endianness.
+        fn first_true_match_byte(
+            found : usize,
+            y : usize,
+        ) -> usize {
+            debug_assert!(0 != found);
+
+            let found_bytes = found.to_ne_bytes();
+            let y_bytes = y.to_ne_bytes();
+
+            found_bytes
+                .iter()
+                .zip(y_bytes.iter())
+                .position(|(&f, &yb)| 0 != f && 0 == yb)
+                .expect("a word for which `found` is non-zero contains at least one genuine zero byte in `y`")
+        }
+
+        /// Locates the address index, within the word, of the
+        /// highest-addressed *genuine* matching byte, given the SWAR
+        /// "found" mask and the `y` (`word ^ mask`) value it was
+        /// derived from.
+        ///
+        /// The borrow out of a genuine zero byte propagates towards
+        /// more significant bytes (on a little-endian target, towards
+        /// higher addresses) and can spuriously set the high bit of the
+        /// very next (non-matching) byte, whenever that byte's value in
+        /// `y` is exactly `1`. So each candidate, from the top down, is
+        /// verified against `y` (a true match's byte in `y` is exactly
+        /// `0`) before being accepted.
+        ///
+        /// Uses `to_ne_bytes()`, so it is correct for either target
+        /// endianness.
+        fn last_true_match_byte(
+            found : usize,
+            y : usize,
+        ) -> usize {
+            debug_assert!(0 != found);
+
+            let found_bytes = found.to_ne_bytes();
+            let y_bytes = y.to_ne_bytes();
+
+            found_bytes
+                .iter()
+                .zip(y_bytes.iter())
+                .enumerate()
+                .rev()
+                .find(|&(_, (&f, &yb))| 0 != f && 0 == yb)
+                .map(|(ix, _)| ix)
+                .expect("a word for which `found` is non-zero contains at least one genuine zero byte in `y`")
+        }
+
+
         /// A slice representation of offset and length.
         #[derive(Clone, Copy)]
         #[derive(Debug)]
@@ -48,6 +363,178 @@ pub mod fastparse {
                     length : len,
                 }
             }
+
+            /// Splits `source` on elements matching `pred`, yielding
+            /// [`PositionalSlice`]s describing each token's position in
+            /// `source` rather than borrowed subslices.
+            ///
+            /// Parameters:
+            /// - `source` - The source slice to split;
+            /// - `pred` - The predicate identifying delimiter elements;
+            pub fn split_of<T, P>(
+                source : &[T],
+                pred : P,
+            ) -> super::iter::SplitPositional<'_, T, P>
+            where
+                P : FnMut(&T) -> bool,
+            {
+                super::iter::SplitPositional::new(source, pred)
+            }
+
+            /// Splits `source` on elements matching `pred`, as
+            /// [`Self::split_of()`], except that each emitted slice
+            /// retains the matched delimiter at its end.
+            ///
+            /// Parameters:
+            /// - `source` - The source slice to split;
+            /// - `pred` - The predicate identifying delimiter elements;
+            pub fn split_inclusive_of<T, P>(
+                source : &[T],
+                pred : P,
+            ) -> super::iter::SplitInclusivePositional<'_, T, P>
+            where
+                P : FnMut(&T) -> bool,
+            {
+                super::iter::SplitInclusivePositional::new(source, pred)
+            }
+
+            /// Yields fixed-width, overlapping [`PositionalSlice`] windows
+            /// of `source`, each of length `size`.
+            ///
+            /// Parameters:
+            /// - `source` - The source slice to window over;
+            /// - `size` - The window size (must be non-zero);
+            pub fn windows_of<T>(
+                source : &[T],
+                size : usize,
+            ) -> super::iter::WindowsPositional<'_, T> {
+                super::iter::WindowsPositional::new(source, size)
+            }
+
+            /// Yields fixed-width, non-overlapping [`PositionalSlice`]
+            /// chunks of `source` (the final chunk may be shorter than
+            /// `size`).
+            ///
+            /// Parameters:
+            /// - `source` - The source slice to chunk;
+            /// - `size` - The chunk size (must be non-zero);
+            pub fn chunks_of<T>(
+                source : &[T],
+                size : usize,
+            ) -> super::iter::ChunksPositional<'_, T> {
+                super::iter::ChunksPositional::new(source, size)
+            }
+
+            /// Finds the first occurrence of `needle` in `source`, via a
+            /// word-at-a-time (SWAR) scan rather than a naive per-byte
+            /// loop, returning a length-1 [`PositionalSlice`] at the
+            /// found offset.
+            ///
+            /// # Parameters:
+            /// - `source` - The source bytes to scan;
+            /// - `needle` - The byte to find;
+            pub fn find_byte_in(
+                source : &[u8],
+                needle : u8,
+            ) -> Option<Self> {
+                let len = source.len();
+
+                // unaligned leading prefix, byte-by-byte
+                let align_offset = source.as_ptr().align_offset(USIZE_BYTES);
+                let prefix_len = std_cmp::min(align_offset, len);
+
+                if let Some(ix) = source[..prefix_len].iter().position(|&b| b == needle) {
+                    return Some(Self::new(ix, 1));
+                }
+
+                // word-at-a-time scan of the aligned body
+                let mask : usize = (needle as usize) * LO_BYTES;
+
+                let mut ix = prefix_len;
+
+                while ix + USIZE_BYTES <= len {
+                    // SAFETY: `ix` is word-aligned (by construction above)
+                    // and `ix + USIZE_BYTES <= len`, so the read is
+                    // in-bounds and properly aligned.
+                    let word = unsafe { *(source.as_ptr().add(ix) as *const usize) };
+
+                    let y = word ^ mask;
+                    let found = y.wrapping_sub(LO_BYTES) & !y & HI_BYTES;
+
+                    if 0 != found {
+                        return Some(Self::new(ix + first_true_match_byte(found, y), 1));
+                    }
+
+                    ix += USIZE_BYTES;
+                }
+
+                // unaligned trailing suffix, byte-by-byte
+                if let Some(jx) = source[ix..].iter().position(|&b| b == needle) {
+                    return Some(Self::new(ix + jx, 1));
+                }
+
+                None
+            }
+
+            /// Finds the last occurrence of `needle` in `source`, via a
+            /// word-at-a-time (SWAR) scan rather than a naive per-byte
+            /// loop, returning a length-1 [`PositionalSlice`] at the
+            /// found offset.
+            ///
+            /// # Parameters:
+            /// - `source` - The source bytes to scan;
+            /// - `needle` - The byte to find;
+            pub fn rfind_byte_in(
+                source : &[u8],
+                needle : u8,
+            ) -> Option<Self> {
+                let len = source.len();
+
+                if 0 == len {
+                    return None;
+                }
+
+                // unaligned trailing suffix, byte-by-byte, from the end
+                let end_misalignment = (source.as_ptr() as usize + len) % USIZE_BYTES;
+                let suffix_len = std_cmp::min(end_misalignment, len);
+                let aligned_end = len - suffix_len;
+
+                for jx in (aligned_end..len).rev() {
+                    if source[jx] == needle {
+                        return Some(Self::new(jx, 1));
+                    }
+                }
+
+                // word-at-a-time scan of the aligned body, from the end
+                let mask : usize = (needle as usize) * LO_BYTES;
+
+                let mut ix = aligned_end;
+
+                while ix >= USIZE_BYTES {
+                    ix -= USIZE_BYTES;
+
+                    // SAFETY: `ix` is word-aligned (by construction above)
+                    // and `ix + USIZE_BYTES <= len`, so the read is
+                    // in-bounds and properly aligned.
+                    let word = unsafe { *(source.as_ptr().add(ix) as *const usize) };
+
+                    let y = word ^ mask;
+                    let found = y.wrapping_sub(LO_BYTES) & !y & HI_BYTES;
+
+                    if 0 != found {
+                        return Some(Self::new(ix + last_true_match_byte(found, y), 1));
+                    }
+                }
+
+                // unaligned leading prefix, byte-by-byte, from the end
+                for jx in (0..ix).rev() {
+                    if source[jx] == needle {
+                        return Some(Self::new(jx, 1));
+                    }
+                }
+
+                None
+            }
         }
 
         // Mutating methods
@@ -69,23 +556,52 @@ pub mod fastparse {
             /// Obtains unchecked a copy of the slice moved by the given
             /// `d`elta.
             ///
+            /// Uses `wrapping_add`/`wrapping_sub`, so the result is
+            /// well-defined (wrapping around `0`/`usize::MAX`) rather
+            /// than panicking in debug builds and silently wrapping in
+            /// release builds. Use [`Self::offset_checked()`] or
+            /// [`Self::offset_saturating()`] where overflow must be
+            /// detected or clamped instead.
+            ///
             /// # Parameters:
             /// - `d` - The delta;
             ///
             /// # Return:
             /// New instance of [`PositionalSlice`] adjusted appropriately.
-            ///
-            /// # Preconditions:
-            /// * `isize <= self.offset` - will panic (in debug) if false
             pub fn offset_unchecked(
                 &self,
                 d : isize,
             ) -> Self {
-                // TODO: determine the right Rust way of doing addition with
                 let new_off : usize = if d < 0 {
-                    self.offset - (-d) as usize
+                    self.offset.wrapping_sub(d.unsigned_abs())
+                } else {
+                    self.offset.wrapping_add(d as usize)
+                };
+
+                Self {
+                    length : self.length,
+                    offset : new_off,
+                }
+            }
+
+            /// Obtains a copy of the slice moved by the given `d`elta,
+            /// saturating at `0` or `usize::MAX` rather than wrapping or
+            /// panicking.
+            ///
+            /// # Parameters:
+            /// - `d` - The delta;
+            ///
+            /// # Return:
+            /// New instance of [`PositionalSlice`] adjusted appropriately,
+            /// with its `offset` clamped to `[0, usize::MAX]`.
+            pub fn offset_saturating(
+                &self,
+                d : isize,
+            ) -> Self {
+                let new_off : usize = if d < 0 {
+                    self.offset.saturating_sub(d.unsigned_abs())
                 } else {
-                    self.offset + d as usize
+                    self.offset.saturating_add(d as usize)
                 };
 
                 Self {
@@ -118,7 +634,7 @@ pub mod fastparse {
                 }
 
                 if d < 0 {
-                    let a = (-d) as usize;
+                    let a = d.unsigned_abs();
 
                     if a > self.offset {
                         // case 1.
@@ -145,6 +661,65 @@ pub mod fastparse {
                 }
             }
 
+            /// Obtains checked a copy of the slice with its length set
+            /// to `new_len`.
+            ///
+            /// # Parameters:
+            /// - `new_len` - The new length;
+            ///
+            /// # Return:
+            /// `Option<PositionalSlice>`, where, if `Some`, it contains
+            /// the appropriately resized slice, or `None` if
+            /// `self.offset + new_len` would exceed `usize::MAX` (the
+            /// same case-3 invariant handled by [`Self::offset_checked()`]).
+            pub fn resize_checked(
+                &self,
+                new_len : usize,
+            ) -> Option<Self> {
+                if new_len > usize::MAX - self.offset {
+                    return None;
+                }
+
+                Some(Self::new(self.offset, new_len))
+            }
+
+            /// Obtains checked a copy of the slice with its length
+            /// increased by `delta`.
+            ///
+            /// # Parameters:
+            /// - `delta` - The amount by which to grow the length;
+            ///
+            /// # Return:
+            /// `Option<PositionalSlice>`, where, if `Some`, it contains
+            /// the grown slice, or `None` on overflow.
+            pub fn grow_checked(
+                &self,
+                delta : usize,
+            ) -> Option<Self> {
+                let new_len = self.length.checked_add(delta)?;
+
+                self.resize_checked(new_len)
+            }
+
+            /// Obtains checked a copy of the slice with its length
+            /// decreased by `delta`.
+            ///
+            /// # Parameters:
+            /// - `delta` - The amount by which to shrink the length;
+            ///
+            /// # Return:
+            /// `Option<PositionalSlice>`, where, if `Some`, it contains
+            /// the shrunk slice, or `None` if `delta` exceeds the current
+            /// length.
+            pub fn shrink_checked(
+                &self,
+                delta : usize,
+            ) -> Option<Self> {
+                let new_len = self.length.checked_sub(delta)?;
+
+                self.resize_checked(new_len)
+            }
+
             /// Applies this positional slice to a slice of arbitrary type,
             /// obtaining a relative slice as a result.
             ///
@@ -176,6 +751,110 @@ pub mod fastparse {
             ) -> &'a str {
                 &slice[self.offset..self.offset + self.length]
             }
+
+            /// Indicates whether this positional slice's `[offset,
+            /// offset + length)` range lies wholly within the bounds of
+            /// `slice`.
+            ///
+            /// # Parameters:
+            /// - `slice` - The slice to check against;
+            pub fn fits_within<T>(
+                &self,
+                slice : &[T],
+            ) -> bool {
+                self.offset <= slice.len() && self.length <= slice.len() - self.offset
+            }
+
+            /// Applies this positional slice to a slice of arbitrary
+            /// type, as [`Self::subslice_of()`], except that `None` is
+            /// returned, rather than panicking, when the slice does not
+            /// [`Self::fits_within()`] `slice`.
+            ///
+            /// # Parameters:
+            /// - `slice` - The slice of which to provide a subslice;
+            ///
+            /// # Return:
+            /// `Some` subslice of `slice` according to the `offset` and
+            /// `length` of the receiving instance, or `None` if out of
+            /// bounds.
+            pub fn subslice_of_checked<'a, T>(
+                &self,
+                slice : &'a [T],
+            ) -> Option<&'a [T]> {
+                if !self.fits_within(slice) {
+                    return None;
+                }
+
+                Some(&slice[self.offset..self.offset + self.length])
+            }
+
+            /// Applies this positional slice to a slice of `str`, as
+            /// [`Self::substring_of()`], except that `None` is returned,
+            /// rather than panicking, when the slice does not
+            /// [`Self::fits_within()`] `slice` or when either endpoint
+            /// does not fall on a UTF-8 character boundary.
+            ///
+            /// # Parameters:
+            /// - `slice` - The slice of which to provide a substring;
+            ///
+            /// # Return:
+            /// `Some` substring of `slice` according to the `offset` and
+            /// `length` of the receiving instance, or `None` if out of
+            /// bounds or not on a `char` boundary.
+            pub fn substring_of_checked<'a>(
+                &self,
+                slice : &'a str,
+            ) -> Option<&'a str> {
+                if !self.fits_within(slice.as_bytes()) {
+                    return None;
+                }
+
+                let end = self.offset + self.length;
+
+                if !slice.is_char_boundary(self.offset) || !slice.is_char_boundary(end) {
+                    return None;
+                }
+
+                Some(&slice[self.offset..end])
+            }
+        }
+
+        // Free functions
+
+        /// Finds the index, within a sorted, non-overlapping run of
+        /// [`PositionalSlice`]s, of the slice whose half-open range
+        /// `[offset, offset + length)` contains `offset`.
+        ///
+        /// # Parameters:
+        /// - `slices` - A run of slices sorted ascending by `offset`,
+        ///   with non-overlapping ranges;
+        /// - `offset` - The absolute offset to locate;
+        ///
+        /// # Return:
+        /// `Some` index of the covering slice, or `None` if `offset`
+        /// falls in a gap between slices or beyond the end of `slices`.
+        pub fn slice_covering(
+            slices : &[PositionalSlice],
+            offset : usize,
+        ) -> Option<usize> {
+            let mut lo = 0;
+            let mut hi = slices.len();
+
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+
+                let candidate = &slices[mid];
+
+                if offset < candidate.offset {
+                    hi = mid;
+                } else if offset >= candidate.offset + candidate.length {
+                    lo = mid + 1;
+                } else {
+                    return Some(mid);
+                }
+            }
+
+            None
         }
 
         // Trait implementations
@@ -332,26 +1011,71 @@ mod tests {
             assert_eq!(PositionalSlice::new(0, 1), ssi2);
         }
 
-        #[cfg(not(debug_assertions))]
+        // wraps, rather than panicking, on underflow -- in both debug
+        // and release builds
         {
             let ssi1 = PositionalSlice::new(0, 1);
 
             let ssi2 = ssi1.offset_unchecked(-1);
 
-            assert_eq!(PositionalSlice::new(std::usize::MAX, 1), ssi2);
+            assert_eq!(PositionalSlice::new(usize::MAX, 1), ssi2);
+        }
+
+        // does not panic (via `(-d) as usize` negation overflow) when
+        // `d` is `isize::MIN`
+        {
+            let ssi1 = PositionalSlice::new(0, 1);
+
+            let ssi2 = ssi1.offset_unchecked(isize::MIN);
+
+            assert_eq!(PositionalSlice::new(0usize.wrapping_sub(isize::MIN.unsigned_abs()), 1), ssi2);
         }
     }
 
-    #[cfg(debug_assertions)]
     #[test]
-    #[should_panic(expected = "attempt to subtract with overflow")]
-    fn PositionalSlice_offset_unchecked_() {
+    fn PositionalSlice_offset_saturating() {
+        {
+            let ssi1 = PositionalSlice::new(0, 1);
+
+            let ssi2 = ssi1.offset_saturating(1);
+
+            assert_eq!(PositionalSlice::new(1, 1), ssi2);
+        }
+
+        {
+            let ssi1 = PositionalSlice::new(1, 1);
+
+            let ssi2 = ssi1.offset_saturating(-1);
+
+            assert_eq!(PositionalSlice::new(0, 1), ssi2);
+        }
+
+        // clamps at 0, rather than wrapping, on underflow
+        {
+            let ssi1 = PositionalSlice::new(0, 1);
+
+            let ssi2 = ssi1.offset_saturating(-1);
+
+            assert_eq!(PositionalSlice::new(0, 1), ssi2);
+        }
+
+        // clamps at usize::MAX, rather than wrapping, on overflow
+        {
+            let ssi1 = PositionalSlice::new(usize::MAX - 2, 1);
+
+            let ssi2 = ssi1.offset_saturating(10);
+
+            assert_eq!(PositionalSlice::new(usize::MAX, 1), ssi2);
+        }
+
+        // does not panic (via `(-d) as usize` negation overflow) when
+        // `d` is `isize::MIN`, and still clamps at 0
         {
             let ssi1 = PositionalSlice::new(0, 1);
 
-            let _ssi2 = ssi1.offset_unchecked(-1);
+            let ssi2 = ssi1.offset_saturating(isize::MIN);
 
-            panic!("should not get here");
+            assert_eq!(PositionalSlice::new(0, 1), ssi2);
         }
     }
 
@@ -399,6 +1123,76 @@ mod tests {
 
             assert!(ssi2.is_none());
         }
+
+        // does not panic (via `(-d) as usize` negation overflow) when
+        // `d` is `isize::MIN`
+        {
+            let ssi1 = PositionalSlice::new(0, 1);
+
+            let ssi2 = ssi1.offset_checked(isize::MIN);
+
+            assert!(ssi2.is_none());
+        }
+    }
+
+    #[test]
+    fn PositionalSlice_resize_checked() {
+        {
+            let ssi1 = PositionalSlice::new(3, 1);
+
+            let ssi2 = ssi1.resize_checked(5);
+
+            assert!(ssi2.is_some());
+            assert_eq!(PositionalSlice::new(3, 5), ssi2.unwrap());
+        }
+
+        {
+            let ssi1 = PositionalSlice::new(usize::MAX - 2, 1);
+
+            let ssi2 = ssi1.resize_checked(3);
+
+            assert!(ssi2.is_none());
+        }
+    }
+
+    #[test]
+    fn PositionalSlice_grow_checked() {
+        {
+            let ssi1 = PositionalSlice::new(3, 1);
+
+            let ssi2 = ssi1.grow_checked(4);
+
+            assert!(ssi2.is_some());
+            assert_eq!(PositionalSlice::new(3, 5), ssi2.unwrap());
+        }
+
+        {
+            let ssi1 = PositionalSlice::new(0, usize::MAX);
+
+            let ssi2 = ssi1.grow_checked(1);
+
+            assert!(ssi2.is_none());
+        }
+    }
+
+    #[test]
+    fn PositionalSlice_shrink_checked() {
+        {
+            let ssi1 = PositionalSlice::new(3, 5);
+
+            let ssi2 = ssi1.shrink_checked(2);
+
+            assert!(ssi2.is_some());
+            assert_eq!(PositionalSlice::new(3, 3), ssi2.unwrap());
+        }
+
+        {
+            let ssi1 = PositionalSlice::new(3, 1);
+
+            let ssi2 = ssi1.shrink_checked(2);
+
+            assert!(ssi2.is_none());
+        }
     }
 
     #[test]
@@ -456,4 +1250,332 @@ mod tests {
             assert_eq!("de", sub);
         }
     }
+
+    #[test]
+    fn PositionalSlice_fits_within() {
+        let source = vec![
+            // insert list:
+            0, 1, 2, 3, 4, 5, 6,
+        ];
+
+        assert!(PositionalSlice::new(2, 2).fits_within(&source));
+        assert!(PositionalSlice::new(0, 7).fits_within(&source));
+        assert!(PositionalSlice::new(7, 0).fits_within(&source));
+
+        assert!(!PositionalSlice::new(6, 2).fits_within(&source));
+        assert!(!PositionalSlice::new(8, 0).fits_within(&source));
+    }
+
+    #[test]
+    fn PositionalSlice_subslice_of_checked() {
+        let source = vec![
+            // insert list:
+            0, 1, 2, 3, 4, 5, 6,
+        ];
+
+        {
+            let ps = PositionalSlice::new(2, 2);
+
+            let sub = ps.subslice_of_checked(&source);
+
+            assert!(sub.is_some());
+            assert_eq!(&[2, 3], sub.unwrap());
+        }
+
+        // out of range
+        {
+            let ps = PositionalSlice::new(6, 2);
+
+            assert!(ps.subslice_of_checked(&source).is_none());
+        }
+    }
+
+    #[test]
+    fn PositionalSlice_substring_of_checked() {
+        let source = "abcdef".to_string();
+
+        {
+            let ps = PositionalSlice::new(2, 2);
+
+            let sub = ps.substring_of_checked(&source);
+
+            assert!(sub.is_some());
+            assert_eq!("cd", sub.unwrap());
+        }
+
+        // out of range
+        {
+            let ps = PositionalSlice::new(5, 5);
+
+            assert!(ps.substring_of_checked(&source).is_none());
+        }
+
+        // endpoint inside a multi-byte UTF-8 sequence
+        {
+            let source = "a\u{1F600}b".to_string(); // 'a', 4-byte emoji, 'b'
+
+            let ps = PositionalSlice::new(0, 2);
+
+            assert!(ps.substring_of_checked(&source).is_none());
+        }
+    }
+
+    #[test]
+    fn PositionalSlice_split_of() {
+        {
+            let source = vec![
+                // insert list:
+                1, 2, 0, 3, 4, 0, 5,
+            ];
+
+            let tokens : Vec<PositionalSlice> = PositionalSlice::split_of(&source, |x : &i32| 0 == *x).collect();
+
+            assert_eq!(3, tokens.len());
+            assert_eq!(PositionalSlice::new(0, 2), tokens[0]);
+            assert_eq!(PositionalSlice::new(3, 2), tokens[1]);
+            assert_eq!(PositionalSlice::new(6, 1), tokens[2]);
+        }
+
+        // trailing empty slice is emitted, matching core `Split`
+        {
+            let source = vec![
+                // insert list:
+                1, 2, 0,
+            ];
+
+            let tokens : Vec<PositionalSlice> = PositionalSlice::split_of(&source, |x : &i32| 0 == *x).collect();
+
+            assert_eq!(2, tokens.len());
+            assert_eq!(PositionalSlice::new(0, 2), tokens[0]);
+            assert_eq!(PositionalSlice::new(3, 0), tokens[1]);
+        }
+
+        // empty source yields a single empty slice
+        {
+            let source : Vec<i32> = vec![];
+
+            let tokens : Vec<PositionalSlice> = PositionalSlice::split_of(&source, |x : &i32| 0 == *x).collect();
+
+            assert_eq!(1, tokens.len());
+            assert_eq!(PositionalSlice::new(0, 0), tokens[0]);
+        }
+    }
+
+    #[test]
+    fn PositionalSlice_split_inclusive_of() {
+        {
+            let source = vec![
+                // insert list:
+                1, 2, 0, 3, 4, 0, 5,
+            ];
+
+            let tokens : Vec<PositionalSlice> = PositionalSlice::split_inclusive_of(&source, |x : &i32| 0 == *x).collect();
+
+            assert_eq!(3, tokens.len());
+            assert_eq!(PositionalSlice::new(0, 3), tokens[0]);
+            assert_eq!(PositionalSlice::new(3, 3), tokens[1]);
+            assert_eq!(PositionalSlice::new(6, 1), tokens[2]);
+        }
+
+        // no trailing empty slice after a final delimiter
+        {
+            let source = vec![
+                // insert list:
+                1, 2, 0,
+            ];
+
+            let tokens : Vec<PositionalSlice> = PositionalSlice::split_inclusive_of(&source, |x : &i32| 0 == *x).collect();
+
+            assert_eq!(1, tokens.len());
+            assert_eq!(PositionalSlice::new(0, 3), tokens[0]);
+        }
+
+        // empty source yields no slices
+        {
+            let source : Vec<i32> = vec![];
+
+            let tokens : Vec<PositionalSlice> = PositionalSlice::split_inclusive_of(&source, |x : &i32| 0 == *x).collect();
+
+            assert!(tokens.is_empty());
+        }
+    }
+
+    #[test]
+    fn PositionalSlice_windows_of() {
+        let source = vec![
+            // insert list:
+            1, 2, 3, 4, 5,
+        ];
+
+        let windows : Vec<PositionalSlice> = PositionalSlice::windows_of(&source, 3).collect();
+
+        assert_eq!(3, windows.len());
+        assert_eq!(PositionalSlice::new(0, 3), windows[0]);
+        assert_eq!(PositionalSlice::new(1, 3), windows[1]);
+        assert_eq!(PositionalSlice::new(2, 3), windows[2]);
+    }
+
+    #[test]
+    fn PositionalSlice_windows_of_too_large() {
+        let source = vec![
+            // insert list:
+            1, 2,
+        ];
+
+        let windows : Vec<PositionalSlice> = PositionalSlice::windows_of(&source, 3).collect();
+
+        assert!(windows.is_empty());
+    }
+
+    #[test]
+    fn PositionalSlice_chunks_of() {
+        let source = vec![
+            // insert list:
+            1, 2, 3, 4, 5,
+        ];
+
+        let chunks : Vec<PositionalSlice> = PositionalSlice::chunks_of(&source, 2).collect();
+
+        assert_eq!(3, chunks.len());
+        assert_eq!(PositionalSlice::new(0, 2), chunks[0]);
+        assert_eq!(PositionalSlice::new(2, 2), chunks[1]);
+        assert_eq!(PositionalSlice::new(4, 1), chunks[2]);
+    }
+
+    #[test]
+    fn PositionalSlice_find_byte_in() {
+        {
+            let source = b"the quick brown fox";
+
+            let found = PositionalSlice::find_byte_in(source, b'q');
+
+            assert!(found.is_some());
+            assert_eq!(PositionalSlice::new(4, 1), found.unwrap());
+        }
+
+        // not present
+        {
+            let source = b"the quick brown fox";
+
+            assert!(PositionalSlice::find_byte_in(source, b'z').is_none());
+        }
+
+        // empty source
+        {
+            assert!(PositionalSlice::find_byte_in(b"", b'x').is_none());
+        }
+
+        // match spans multiple words and various alignments/lengths
+        for len in 0..40 {
+            let mut source = vec![b'a'; len];
+
+            for needle_ix in 0..len {
+                source[needle_ix] = b'!';
+
+                assert_eq!(Some(PositionalSlice::new(needle_ix, 1)), PositionalSlice::find_byte_in(&source, b'!'));
+
+                source[needle_ix] = b'a';
+            }
+        }
+    }
+
+    #[test]
+    fn PositionalSlice_rfind_byte_in() {
+        {
+            let source = b"the quick brown fox jumps";
+
+            let found = PositionalSlice::rfind_byte_in(source, b'o');
+
+            assert!(found.is_some());
+            assert_eq!(PositionalSlice::new(17, 1), found.unwrap());
+        }
+
+        // not present
+        {
+            let source = b"the quick brown fox";
+
+            assert!(PositionalSlice::rfind_byte_in(source, b'z').is_none());
+        }
+
+        // empty source
+        {
+            assert!(PositionalSlice::rfind_byte_in(b"", b'x').is_none());
+        }
+
+        // match spans multiple words and various alignments/lengths
+        for len in 0..40 {
+            let mut source = vec![b'a'; len];
+
+            for needle_ix in 0..len {
+                source[needle_ix] = b'!';
+
+                assert_eq!(Some(PositionalSlice::new(needle_ix, 1)), PositionalSlice::rfind_byte_in(&source, b'!'));
+
+                source[needle_ix] = b'a';
+            }
+        }
+
+        // regression: a run of matches immediately followed by
+        // `needle ^ 0x01` used to trip a false positive in the SWAR
+        // "found" mask -- the borrow out of the last genuine match
+        // propagates into the following byte's high bit whenever that
+        // byte's original value is exactly 1 relative to the needle.
+        // `rfind_byte_in` must select the last *genuine* match, not
+        // that contaminated byte.
+        {
+            let source = [33u8, 33, 33, 33, 33, 33, 33, 32];
+
+            assert_eq!(Some(PositionalSlice::new(6, 1)), PositionalSlice::rfind_byte_in(&source, 33));
+        }
+
+        // same regression, swept across run lengths and leading
+        // (mis)alignments, so the contaminated byte lands at every
+        // possible position within an aligned word
+        for prefix_len in 0..16 {
+            for run_len in 1..8 {
+                let needle = b'!';
+
+                let mut source = vec![b'a'; prefix_len];
+
+                source.extend(std::iter::repeat_n(needle, run_len));
+                source.push(needle ^ 0x01);
+
+                let last_match_ix = prefix_len + run_len - 1;
+
+                assert_eq!(Some(PositionalSlice::new(last_match_ix, 1)), PositionalSlice::rfind_byte_in(&source, needle));
+            }
+        }
+    }
+
+    #[test]
+    fn slice_covering() {
+        use super::fastparse::types::slice_covering;
+
+        let slices = vec![
+            // insert list:
+            PositionalSlice::new(0, 3),
+            PositionalSlice::new(3, 4),
+            PositionalSlice::new(10, 2),
+        ];
+
+        // within the first slice
+        assert_eq!(Some(0), slice_covering(&slices, 0));
+        assert_eq!(Some(0), slice_covering(&slices, 2));
+
+        // within the middle slice
+        assert_eq!(Some(1), slice_covering(&slices, 3));
+        assert_eq!(Some(1), slice_covering(&slices, 6));
+
+        // within the final slice
+        assert_eq!(Some(2), slice_covering(&slices, 11));
+
+        // a gap between slices
+        assert_eq!(None, slice_covering(&slices, 8));
+
+        // beyond the end
+        assert_eq!(None, slice_covering(&slices, 12));
+
+        // an empty run
+        assert_eq!(None, slice_covering(&[], 0));
+    }
 }